@@ -0,0 +1,934 @@
+//! Binary Fusion Tap - Rust Implementation
+//! Quantum-inspired key generation using 8-fold Heartbeat and ZPE Overflow
+
+use std::fmt;
+
+use rand_core::{Error as RandError, RngCore, SeedableRng};
+
+const CHACHA_CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+fn chacha_quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] ^= state[a];
+    state[d] = state[d].rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] ^= state[c];
+    state[b] = state[b].rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]);
+    state[d] ^= state[a];
+    state[d] = state[d].rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] ^= state[c];
+    state[b] = state[b].rotate_left(7);
+}
+
+/// One 64-byte ChaCha20 keystream block for `seed` at block index `counter`.
+fn chacha_block(seed: &[u32; 8], counter: u64) -> [u8; 64] {
+    let mut state = [0u32; 16];
+    state[0..4].copy_from_slice(&CHACHA_CONSTANTS);
+    state[4..12].copy_from_slice(seed);
+    state[12] = counter as u32;
+    state[13] = (counter >> 32) as u32;
+    state[14] = 0;
+    state[15] = 0;
+    let initial = state;
+
+    for _ in 0..10 {
+        chacha_quarter_round(&mut state, 0, 4, 8, 12);
+        chacha_quarter_round(&mut state, 1, 5, 9, 13);
+        chacha_quarter_round(&mut state, 2, 6, 10, 14);
+        chacha_quarter_round(&mut state, 3, 7, 11, 15);
+        chacha_quarter_round(&mut state, 0, 5, 10, 15);
+        chacha_quarter_round(&mut state, 1, 6, 11, 12);
+        chacha_quarter_round(&mut state, 2, 7, 8, 13);
+        chacha_quarter_round(&mut state, 3, 4, 9, 14);
+    }
+
+    let mut out = [0u8; 64];
+    for (i, word) in state.iter().enumerate() {
+        let mixed = word.wrapping_add(initial[i]);
+        out[i * 4..i * 4 + 4].copy_from_slice(&mixed.to_le_bytes());
+    }
+    out
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const SHA256_H0: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+/// Minimal SHA-256 (FIPS 180-4), kept in-crate so the fusion pipeline has no
+/// external hashing dependency.
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut message = data.to_vec();
+    let bit_len = (data.len() as u64) * 8;
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&bit_len.to_be_bytes());
+
+    let mut h = SHA256_H0;
+    for chunk in message.chunks_exact(64) {
+        let mut w = [0u32; 64];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = u32::from_be_bytes(chunk[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh] = h;
+        for i in 0..64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ ((!e) & g);
+            let temp1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+        h[5] = h[5].wrapping_add(f);
+        h[6] = h[6].wrapping_add(g);
+        h[7] = h[7].wrapping_add(hh);
+    }
+
+    let mut out = [0u8; 32];
+    for (i, word) in h.iter().enumerate() {
+        out[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+    }
+    out
+}
+
+#[derive(Debug, Clone)]
+pub struct BinaryFusionResult {
+    pub k: u64,
+    pub seed_value: u128,
+    pub binary_seed: String,
+    pub tap_state: u128,
+    pub zpe_overflow: u128,
+}
+
+impl fmt::Display for BinaryFusionResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "K: {}\nSeed: {}\nTap State: {:b}\nZPE Overflow: {:b}",
+            self.k, self.seed_value, self.tap_state, self.zpe_overflow
+        )
+    }
+}
+
+/// Seed derived from the concatenated digit sequence `1..=k`.
+fn digit_seed(k: u64) -> u128 {
+    let mut seed_str = String::new();
+    for i in 1..=k {
+        seed_str.push_str(&i.to_string());
+    }
+    seed_str.parse().expect("Failed to parse seed")
+}
+
+/// Run the 8-fold heartbeat / phase-offset / ZPE-overflow pipeline over a
+/// given `seed_val`, shared by [`binary_fusion_tap`] and
+/// [`binary_fusion_tap_from_phrase`].
+fn fuse_seed(seed_val: u128, k: u64) -> BinaryFusionResult {
+    // 2. Apply 8-fold Heartbeat (bit-shift left by 3)
+    let heartbeat_val = seed_val.wrapping_shl(3);
+
+    // 3. Add Phase Offset
+    let manifested = heartbeat_val.wrapping_add(k as u128);
+
+    // 4. Extract ZPE Overflow
+    let overflow = if k < 10 {
+        0
+    } else {
+        manifested ^ seed_val.wrapping_mul(8)
+    };
+
+    BinaryFusionResult {
+        k,
+        seed_value: seed_val,
+        binary_seed: format!("{:b}", seed_val),
+        tap_state: manifested,
+        zpe_overflow: overflow,
+    }
+}
+
+/// Generate binary fusion tap with 8-fold heartbeat and ZPE overflow
+///
+/// # Arguments
+/// * `k` - Tap parameter (recommended: 11 for optimal entropy)
+///
+/// # Returns
+/// * `BinaryFusionResult` - Key generation data
+pub fn binary_fusion_tap(k: u64) -> BinaryFusionResult {
+    // 1. Generate seed from concatenated sequence
+    fuse_seed(digit_seed(k), k)
+}
+
+/// Number of absorb-loop hash iterations used to stretch a passphrase.
+/// Kept deliberately high to slow brute-force guessing of short phrases.
+const PHRASE_ABSORB_ITERATIONS: u32 = 10_000;
+
+/// Fold a passphrase into a 128-bit seed by iteratively hashing its UTF-8
+/// bytes, taking the first 16 bytes of the final SHA-256 state.
+fn phrase_seed(phrase: &str, iterations: u32) -> u128 {
+    let mut state = sha256(phrase.as_bytes());
+    for _ in 1..iterations {
+        state = sha256(&state);
+    }
+    u128::from_be_bytes(state[..16].try_into().unwrap())
+}
+
+/// Like [`binary_fusion_tap`], but mixes a human passphrase into the seed
+/// before the 8-fold heartbeat shift, so the output depends on more than
+/// the predictable integer `k`.
+///
+/// # Arguments
+/// * `phrase` - Passphrase whose bytes are absorbed into the seed
+/// * `k` - Tap parameter (recommended: 11 for optimal entropy)
+pub fn binary_fusion_tap_from_phrase(phrase: &str, k: u64) -> BinaryFusionResult {
+    let seed_val = digit_seed(k) ^ phrase_seed(phrase, PHRASE_ABSORB_ITERATIONS);
+    fuse_seed(seed_val, k)
+}
+
+/// String encodings for a [`BinaryFusionResult`], so fusion output can be
+/// copy-pasted as a checksummed, address-style string instead of raw binary.
+pub mod encoding {
+    use super::{sha256, BinaryFusionResult};
+
+    pub(crate) const BASE58_ALPHABET: &[u8] =
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+    /// One request to render a [`BinaryFusionResult`] as a string, naming
+    /// both the wire format and the parameters it needs.
+    #[derive(Debug, Clone)]
+    pub enum Encoding {
+        Base58Check { version: Vec<u8>, suffix: Vec<u8> },
+        Bech32 { hrp: String },
+    }
+
+    impl Encoding {
+        pub fn encode(&self, result: &BinaryFusionResult) -> String {
+            match self {
+                Encoding::Base58Check { version, suffix } => {
+                    result.to_base58check(version, suffix)
+                }
+                Encoding::Bech32 { hrp } => result.to_bech32(hrp),
+            }
+        }
+    }
+
+    impl BinaryFusionResult {
+        /// Base58Check-encode `version || tap_state (big-endian) || suffix`,
+        /// with a 4-byte double-SHA-256 checksum appended before encoding.
+        pub fn to_base58check(&self, version: &[u8], suffix: &[u8]) -> String {
+            base58check(version, &self.tap_state.to_be_bytes(), suffix)
+        }
+
+        /// Like [`BinaryFusionResult::to_base58check`], but keys off
+        /// `zpe_overflow` instead of `tap_state`.
+        pub fn to_base58check_overflow(&self, version: &[u8], suffix: &[u8]) -> String {
+            base58check(version, &self.zpe_overflow.to_be_bytes(), suffix)
+        }
+
+        /// Bech32-encode the big-endian bytes of `tap_state` under `hrp`.
+        pub fn to_bech32(&self, hrp: &str) -> String {
+            bech32_encode(hrp, &self.tap_state.to_be_bytes())
+        }
+
+        /// Like [`BinaryFusionResult::to_bech32`], but keys off
+        /// `zpe_overflow` instead of `tap_state`.
+        pub fn to_bech32_overflow(&self, hrp: &str) -> String {
+            bech32_encode(hrp, &self.zpe_overflow.to_be_bytes())
+        }
+    }
+
+    fn base58check(version: &[u8], key: &[u8], suffix: &[u8]) -> String {
+        let mut payload = Vec::with_capacity(version.len() + key.len() + suffix.len() + 4);
+        payload.extend_from_slice(version);
+        payload.extend_from_slice(key);
+        payload.extend_from_slice(suffix);
+        let checksum = sha256(&sha256(&payload));
+        payload.extend_from_slice(&checksum[..4]);
+        base58_encode(&payload)
+    }
+
+    fn base58_encode(input: &[u8]) -> String {
+        let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+
+        let mut digits: Vec<u8> = Vec::new();
+        for &byte in input {
+            let mut carry = byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+
+        let mut encoded = String::with_capacity(leading_zeros + digits.len());
+        encoded.extend(std::iter::repeat_n('1', leading_zeros));
+        encoded.extend(digits.iter().rev().map(|&d| BASE58_ALPHABET[d as usize] as char));
+        encoded
+    }
+
+    fn bech32_polymod(values: &[u8]) -> u32 {
+        const GENERATORS: [u32; 5] = [
+            0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3,
+        ];
+        let mut chk: u32 = 1;
+        for &value in values {
+            let top = chk >> 25;
+            chk = ((chk & 0x1ff_ffff) << 5) ^ (value as u32);
+            for (i, gen) in GENERATORS.iter().enumerate() {
+                if (top >> i) & 1 == 1 {
+                    chk ^= gen;
+                }
+            }
+        }
+        chk
+    }
+
+    fn bech32_hrp_expand(hrp: &str) -> Vec<u8> {
+        let mut expanded: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+        expanded.push(0);
+        expanded.extend(hrp.bytes().map(|b| b & 0x1f));
+        expanded
+    }
+
+    /// Regroup `data` from 8-bit to 5-bit words, padding the final group
+    /// with trailing zero bits.
+    fn convert_bits_8_to_5(data: &[u8]) -> Vec<u8> {
+        let mut acc: u32 = 0;
+        let mut bits: u32 = 0;
+        let mut out = Vec::new();
+        for &byte in data {
+            acc = (acc << 8) | (byte as u32);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(((acc >> bits) & 0x1f) as u8);
+            }
+        }
+        if bits > 0 {
+            out.push(((acc << (5 - bits)) & 0x1f) as u8);
+        }
+        out
+    }
+
+    fn bech32_encode(hrp: &str, data: &[u8]) -> String {
+        let values = convert_bits_8_to_5(data);
+
+        let mut combined = bech32_hrp_expand(hrp);
+        combined.extend_from_slice(&values);
+        combined.extend_from_slice(&[0u8; 6]);
+        let polymod = bech32_polymod(&combined) ^ 1;
+        let checksum: Vec<u8> = (0..6)
+            .map(|i| ((polymod >> (5 * (5 - i))) & 0x1f) as u8)
+            .collect();
+
+        let mut encoded = String::with_capacity(hrp.len() + 1 + values.len() + checksum.len());
+        encoded.push_str(hrp);
+        encoded.push('1');
+        encoded.extend(
+            values
+                .iter()
+                .chain(checksum.iter())
+                .map(|&v| BECH32_CHARSET[v as usize] as char),
+        );
+        encoded
+    }
+}
+
+/// Perturb the digit-derived seed for `k` with a `nonce`, then run the
+/// usual fusion pipeline. Unlike [`binary_fusion_tap_from_phrase`] this
+/// skips the slow passphrase absorb loop, so it's cheap enough to call
+/// millions of times during a [`find_vanity`] search.
+fn fuse_seed_with_nonce(k: u64, nonce: u64) -> BinaryFusionResult {
+    fuse_seed(digit_seed(k) ^ (nonce as u128), k)
+}
+
+/// Upper bound on how many candidates [`find_vanity`] will try across all
+/// worker threads before giving up.
+const VANITY_MAX_ATTEMPTS: u64 = 300_000;
+
+/// Tap parameter values tried while vanity-searching. `k` must stay at or
+/// above 10 or `zpe_overflow` is always zero, and at most 24 or the
+/// concatenated digit seed overflows `u128`.
+const VANITY_K_RANGE: std::ops::Range<u64> = 10..24;
+
+/// Search the tap parameter space for a fusion key whose encoding starts
+/// with `prefix`.
+///
+/// Each attempt perturbs the seed for a `k` drawn from [`VANITY_K_RANGE`]
+/// with an incrementing nonce (see [`fuse_seed_with_nonce`]). The search is
+/// spread across all available CPU cores; the first worker to find a match
+/// flips a shared stop flag so the rest exit early. Gives up after
+/// [`VANITY_MAX_ATTEMPTS`] total candidates.
+pub fn find_vanity(prefix: &str, encoding: encoding::Encoding) -> Option<BinaryFusionResult> {
+    let worker_count = std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1);
+    let found = std::sync::atomic::AtomicBool::new(false);
+    let next_attempt = std::sync::atomic::AtomicU64::new(0);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..worker_count)
+            .map(|_| {
+                let found = &found;
+                let next_attempt = &next_attempt;
+                let encoding = &encoding;
+                scope.spawn(move || loop {
+                    if found.load(std::sync::atomic::Ordering::Relaxed) {
+                        return None;
+                    }
+                    let attempt = next_attempt.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    if attempt >= VANITY_MAX_ATTEMPTS {
+                        return None;
+                    }
+
+                    let span = VANITY_K_RANGE.end - VANITY_K_RANGE.start;
+                    let k = VANITY_K_RANGE.start + (attempt % span);
+                    let candidate = fuse_seed_with_nonce(k, attempt);
+                    if encoding.encode(&candidate).starts_with(prefix) {
+                        found.store(true, std::sync::atomic::Ordering::Relaxed);
+                        return Some(candidate);
+                    }
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .find_map(|worker| worker.join().unwrap())
+    })
+}
+
+/// Shamir secret sharing over GF(256), for splitting a fusion key's bytes
+/// across multiple custodians and recovering it from a threshold of them.
+pub mod shares {
+    use std::fmt;
+
+    use rand_core::RngCore;
+
+    /// One `(x, y)` point of a split secret, tagged with the threshold it
+    /// was split with so [`combine`] can tell whether enough were supplied.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Share {
+        pub x: u8,
+        pub threshold: u8,
+        pub y: Vec<u8>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ShareError {
+        EmptySecret,
+        InvalidThreshold,
+        NotEnoughShares { have: usize, need: u8 },
+        InconsistentShares,
+    }
+
+    impl fmt::Display for ShareError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ShareError::EmptySecret => write!(f, "secret must be non-empty"),
+                ShareError::InvalidThreshold => {
+                    write!(f, "threshold must be nonzero and no greater than the share count")
+                }
+                ShareError::NotEnoughShares { have, need } => {
+                    write!(f, "need {need} distinct shares to reconstruct, got {have}")
+                }
+                ShareError::InconsistentShares => write!(
+                    f,
+                    "shares do not all come from the same split (mismatched threshold or secret length)"
+                ),
+            }
+        }
+    }
+
+    impl std::error::Error for ShareError {}
+
+    /// Multiply two GF(2^8) elements, reducing modulo the AES polynomial
+    /// `x^8 + x^4 + x^3 + x + 1` (0x11B).
+    fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = 0u8;
+        for _ in 0..8 {
+            if b & 1 != 0 {
+                product ^= a;
+            }
+            let carry = a & 0x80 != 0;
+            a <<= 1;
+            if carry {
+                a ^= 0x1B;
+            }
+            b >>= 1;
+        }
+        product
+    }
+
+    fn gf_pow(a: u8, mut exponent: u8) -> u8 {
+        let mut result = 1u8;
+        let mut base = a;
+        while exponent > 0 {
+            if exponent & 1 != 0 {
+                result = gf_mul(result, base);
+            }
+            base = gf_mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse of a nonzero GF(2^8) element: `GF(256)*` has
+    /// order 255, so `a^254 == a^-1`.
+    fn gf_inv(a: u8) -> u8 {
+        assert!(a != 0, "zero has no multiplicative inverse in GF(256)");
+        gf_pow(a, 254)
+    }
+
+    fn random_byte() -> u8 {
+        let mut byte = [0u8; 1];
+        rand::rngs::OsRng.fill_bytes(&mut byte);
+        byte[0]
+    }
+
+    /// Evaluate a GF(256) polynomial (constant term first) at `x` via
+    /// Horner's method.
+    fn eval_poly(coefficients: &[u8], x: u8) -> u8 {
+        coefficients
+            .iter()
+            .rev()
+            .fold(0u8, |acc, &coeff| gf_mul(acc, x) ^ coeff)
+    }
+
+    fn lagrange_interpolate_at_zero(points: &[(u8, u8)]) -> u8 {
+        let mut result = 0u8;
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    numerator = gf_mul(numerator, xj);
+                    // -xj == xj in characteristic 2.
+                    denominator = gf_mul(denominator, xi ^ xj);
+                }
+            }
+            result ^= gf_mul(yi, gf_mul(numerator, gf_inv(denominator)));
+        }
+        result
+    }
+
+    /// Split `secret` into `n` shares, any `t` of which can reconstruct it.
+    ///
+    /// For each byte of the secret, builds a degree-`t-1` polynomial with
+    /// the secret byte as the constant term and random GF(256) coefficients,
+    /// then evaluates it at `x = 1..=n`.
+    pub fn split(secret: &[u8], t: u8, n: u8) -> Result<Vec<Share>, ShareError> {
+        if secret.is_empty() {
+            return Err(ShareError::EmptySecret);
+        }
+        if t == 0 || n == 0 || t > n {
+            return Err(ShareError::InvalidThreshold);
+        }
+
+        let polynomials: Vec<Vec<u8>> = secret
+            .iter()
+            .map(|&secret_byte| {
+                let mut poly = Vec::with_capacity(t as usize);
+                poly.push(secret_byte);
+                poly.extend((1..t).map(|_| random_byte()));
+                poly
+            })
+            .collect();
+
+        Ok((1..=n)
+            .map(|x| Share {
+                x,
+                threshold: t,
+                y: polynomials.iter().map(|poly| eval_poly(poly, x)).collect(),
+            })
+            .collect())
+    }
+
+    /// Reconstruct a secret from a threshold of its shares via Lagrange
+    /// interpolation at `x = 0`.
+    pub fn combine(shares: &[Share]) -> Result<Vec<u8>, ShareError> {
+        let mut distinct: Vec<&Share> = Vec::new();
+        for share in shares {
+            if !distinct.iter().any(|s| s.x == share.x) {
+                distinct.push(share);
+            }
+        }
+
+        let Some(first) = distinct.first() else {
+            return Err(ShareError::NotEnoughShares { have: 0, need: 1 });
+        };
+        let threshold = first.threshold;
+        let secret_len = first.y.len();
+        if distinct
+            .iter()
+            .any(|s| s.threshold != threshold || s.y.len() != secret_len)
+        {
+            return Err(ShareError::InconsistentShares);
+        }
+        if distinct.len() < threshold as usize {
+            return Err(ShareError::NotEnoughShares {
+                have: distinct.len(),
+                need: threshold,
+            });
+        }
+
+        Ok((0..secret_len)
+            .map(|byte_index| {
+                let points: Vec<(u8, u8)> =
+                    distinct.iter().map(|s| (s.x, s.y[byte_index])).collect();
+                lagrange_interpolate_at_zero(&points)
+            })
+            .collect())
+    }
+}
+
+/// Deterministic keystream RNG driven by a [`BinaryFusionResult`].
+///
+/// The 16 bytes of `tap_state` and the 16 bytes of `zpe_overflow` are
+/// concatenated into a 32-byte seed and expanded with a ChaCha20-style
+/// counter-mode core, so fusion output can drive any `rand`-ecosystem API
+/// that consumes an `RngCore`.
+pub struct FusionSeedStream {
+    seed: [u32; 8],
+    counter: u64,
+    block: [u8; 64],
+    block_pos: usize,
+}
+
+impl FusionSeedStream {
+    /// Seed a stream straight from a [`binary_fusion_tap`] result.
+    pub fn from_fusion(result: BinaryFusionResult) -> Self {
+        let mut seed = [0u8; 32];
+        seed[..16].copy_from_slice(&result.tap_state.to_be_bytes());
+        seed[16..].copy_from_slice(&result.zpe_overflow.to_be_bytes());
+        Self::from_seed(seed)
+    }
+
+    fn refill(&mut self) {
+        self.block = chacha_block(&self.seed, self.counter);
+        self.counter = self.counter.wrapping_add(1);
+        self.block_pos = 0;
+    }
+}
+
+impl SeedableRng for FusionSeedStream {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut words = [0u32; 8];
+        for (word, chunk) in words.iter_mut().zip(seed.chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        Self {
+            seed: words,
+            counter: 0,
+            block: [0u8; 64],
+            block_pos: 64,
+        }
+    }
+}
+
+impl RngCore for FusionSeedStream {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut filled = 0;
+        while filled < dest.len() {
+            if self.block_pos == self.block.len() {
+                self.refill();
+            }
+            let available = self.block.len() - self.block_pos;
+            let take = available.min(dest.len() - filled);
+            dest[filled..filled + take]
+                .copy_from_slice(&self.block[self.block_pos..self.block_pos + take]);
+            self.block_pos += take;
+            filled += take;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RandError> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k11() {
+        let result = binary_fusion_tap(11);
+        assert_eq!(result.k, 11);
+        assert_eq!(result.seed_value, 1234567891011);
+        assert_eq!(result.zpe_overflow, 59);
+    }
+
+    #[test]
+    fn fusion_seed_stream_is_deterministic() {
+        let mut a = FusionSeedStream::from_fusion(binary_fusion_tap(11));
+        let mut b = FusionSeedStream::from_fusion(binary_fusion_tap(11));
+        let mut out_a = [0u8; 128];
+        let mut out_b = [0u8; 128];
+        a.fill_bytes(&mut out_a);
+        b.fill_bytes(&mut out_b);
+        assert_eq!(out_a, out_b);
+    }
+
+    #[test]
+    fn phrase_tap_is_deterministic_and_phrase_dependent() {
+        let a = binary_fusion_tap_from_phrase("correct horse battery staple", 11);
+        let b = binary_fusion_tap_from_phrase("correct horse battery staple", 11);
+        let c = binary_fusion_tap_from_phrase("different phrase", 11);
+        assert_eq!(a.tap_state, b.tap_state);
+        assert_eq!(a.zpe_overflow, b.zpe_overflow);
+        assert_ne!(a.tap_state, c.tap_state);
+        assert_ne!(a.tap_state, binary_fusion_tap(11).tap_state);
+    }
+
+    #[test]
+    fn base58check_round_trips_through_checksum() {
+        let result = binary_fusion_tap(11);
+        let encoded = result.to_base58check(&[0x00], &[]);
+        assert!(encoded.chars().all(|c| encoding::BASE58_ALPHABET.contains(&(c as u8))));
+
+        let mut decoded = base58_decode(&encoded);
+        let checksum = decoded.split_off(decoded.len() - 4);
+        let expected = sha256(&sha256(&decoded));
+        assert_eq!(checksum, expected[..4]);
+    }
+
+    #[test]
+    fn base58check_differs_by_key_source() {
+        let result = binary_fusion_tap(11);
+        assert_ne!(
+            result.to_base58check(&[0x00], &[]),
+            result.to_base58check_overflow(&[0x00], &[])
+        );
+    }
+
+    #[test]
+    fn bech32_has_expected_hrp_and_separator() {
+        let result = binary_fusion_tap(11);
+        let encoded = result.to_bech32("fusion");
+        assert!(encoded.starts_with("fusion1"));
+        assert_ne!(encoded, result.to_bech32_overflow("fusion"));
+    }
+
+    fn base58_decode(input: &str) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![0];
+        for c in input.chars() {
+            let digit = encoding::BASE58_ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .expect("invalid base58 character") as u32;
+            let mut carry = digit;
+            for byte in bytes.iter_mut() {
+                carry += (*byte as u32) * 58;
+                *byte = (carry & 0xff) as u8;
+                carry >>= 8;
+            }
+            while carry > 0 {
+                bytes.push((carry & 0xff) as u8);
+                carry >>= 8;
+            }
+        }
+        for c in input.chars() {
+            if c == '1' {
+                bytes.push(0);
+            } else {
+                break;
+            }
+        }
+        bytes.reverse();
+        bytes
+    }
+
+    #[test]
+    fn find_vanity_matches_a_constrained_bech32_prefix() {
+        // "fusion1" is just the hrp plus separator and would match any
+        // encoding, and so would "fusion1q": every candidate key has leading
+        // zero bits. "qa" constrains the first two data characters, which
+        // only the k = 23 candidates meet, so the search has to iterate past
+        // the first attempt.
+        let found = find_vanity(
+            "fusion1qa",
+            encoding::Encoding::Bech32 {
+                hrp: "fusion".to_string(),
+            },
+        );
+        assert!(found.is_some());
+    }
+
+    #[test]
+    fn find_vanity_gives_up_on_an_impossible_prefix() {
+        let impossible = "!".repeat(200);
+        let found = find_vanity(
+            &impossible,
+            encoding::Encoding::Bech32 {
+                hrp: "fusion".to_string(),
+            },
+        );
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn shares_split_and_combine_round_trip() {
+        let result = binary_fusion_tap(11);
+        let secret = result.tap_state.to_be_bytes();
+
+        let split = shares::split(&secret, 3, 5).unwrap();
+        assert_eq!(split.len(), 5);
+
+        let recovered = shares::combine(&split[1..4]).unwrap();
+        assert_eq!(recovered, secret);
+    }
+
+    #[test]
+    fn shares_combine_rejects_too_few_shares() {
+        let secret = [1, 2, 3, 4];
+        let split = shares::split(&secret, 3, 5).unwrap();
+        let err = shares::combine(&split[..2]).unwrap_err();
+        assert_eq!(
+            err,
+            shares::ShareError::NotEnoughShares { have: 2, need: 3 }
+        );
+    }
+
+    #[test]
+    fn shares_combine_dedupes_repeated_shares() {
+        let secret = [9, 8, 7];
+        let split = shares::split(&secret, 2, 4).unwrap();
+        let repeated = vec![split[0].clone(), split[0].clone(), split[0].clone()];
+        let err = shares::combine(&repeated).unwrap_err();
+        assert_eq!(
+            err,
+            shares::ShareError::NotEnoughShares { have: 1, need: 2 }
+        );
+    }
+
+    #[test]
+    fn shares_combine_rejects_shares_from_different_splits() {
+        let a = shares::split(&[1, 2, 3, 4], 2, 3).unwrap();
+        let b = shares::split(&[5, 6, 7], 2, 3).unwrap();
+        let mixed = vec![a[0].clone(), b[1].clone()];
+        let err = shares::combine(&mixed).unwrap_err();
+        assert_eq!(err, shares::ShareError::InconsistentShares);
+    }
+
+    #[test]
+    fn fusion_seed_stream_spans_multiple_blocks() {
+        let mut rng = FusionSeedStream::from_fusion(binary_fusion_tap(11));
+        let mut out = [0u8; 256];
+        rng.fill_bytes(&mut out);
+        assert!(out[..64] != out[64..128]);
+    }
+}
+
+/// Post-quantum key generation backed by ML-KEM (FIPS 203), gated behind the
+/// `pqc` feature so the crate stays dependency-light by default.
+#[cfg(feature = "pqc")]
+pub mod pqc {
+    use ml_kem::{KemCore, MlKem768, B32};
+
+    use super::{BinaryFusionResult, FusionSeedStream};
+    use rand_core::RngCore;
+
+    impl BinaryFusionResult {
+        /// Expand `tap_state` and `zpe_overflow` through the same XOF
+        /// keystream that backs [`FusionSeedStream`] into the 64-byte
+        /// `(d, z)` seed pair ML-KEM KeyGen (FIPS 203) expects.
+        pub fn to_ml_kem_seed(&self) -> [u8; 64] {
+            let mut rng = FusionSeedStream::from_fusion(self.clone());
+            let mut seed = [0u8; 64];
+            rng.fill_bytes(&mut seed);
+            seed
+        }
+    }
+
+    /// Derive a reproducible ML-KEM-768 keypair straight from a fusion tap
+    /// result, so a chosen `k` (or passphrase) always yields the same
+    /// encapsulation/decapsulation keys.
+    pub fn fusion_ml_kem_keygen(
+        result: &BinaryFusionResult,
+    ) -> (
+        <MlKem768 as KemCore>::DecapsulationKey,
+        <MlKem768 as KemCore>::EncapsulationKey,
+    ) {
+        let seed = result.to_ml_kem_seed();
+        let d = B32::try_from(&seed[..32]).expect("d is 32 bytes");
+        let z = B32::try_from(&seed[32..]).expect("z is 32 bytes");
+        MlKem768::generate_deterministic(&d, &z)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::binary_fusion_tap;
+
+        #[test]
+        fn fusion_ml_kem_keygen_is_deterministic() {
+            let result = binary_fusion_tap(11);
+            let (dk_a, ek_a) = fusion_ml_kem_keygen(&result);
+            let (dk_b, ek_b) = fusion_ml_kem_keygen(&result);
+            assert_eq!(dk_a, dk_b);
+            assert_eq!(ek_a, ek_b);
+        }
+    }
+}
+